@@ -1,8 +1,15 @@
 extern crate uuid as uuid_ext;
+// `new_v5`/`new_v7` below need `rand` and `sha1` declared as dependencies
+// in this crate's Cargo.toml.
+extern crate rand as rand_ext;
+extern crate sha1 as sha1_ext;
+#[cfg(feature = "serde")]
+extern crate serde as serde_ext;
 
 use std::fmt;
 use std::str::FromStr;
 use std::ops::Deref;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use rocket::request::{FromParam, FromFormValue};
 use rocket::http::RawStr;
@@ -69,6 +76,157 @@ impl UUID {
     pub fn into_inner(self) -> uuid_ext::Uuid {
         self.0
     }
+
+    /// Generates a random (version 4) UUID.
+    ///
+    /// # Example
+    /// ```rust
+    /// # extern crate rocket_contrib;
+    /// # use rocket_contrib::UUID;
+    /// # fn main() {
+    /// let id = UUID::new_v4();
+    /// # let _ = id;
+    /// # }
+    /// ```
+    pub fn new_v4() -> UUID {
+        UUID(uuid_ext::Uuid::new_v4())
+    }
+
+    /// Generates a deterministic, name-based (version 5) UUID by hashing
+    /// `namespace` and `name` together with SHA-1. Calling this again with
+    /// the same `namespace` and `name` always yields the same UUID.
+    ///
+    /// `name` is taken as a byte slice rather than `&str` so that non-UTF8
+    /// names can be hashed too. Valid UTF-8 delegates to the `uuid` crate's
+    /// own `Uuid::new_v5`; non-UTF8 input falls back to hashing the raw
+    /// bytes directly with the same SHA-1 + version/variant-bit packing.
+    pub fn new_v5(namespace: &UUID, name: &[u8]) -> UUID {
+        if let Ok(name) = ::std::str::from_utf8(name) {
+            return UUID(uuid_ext::Uuid::new_v5(&namespace.0, name));
+        }
+
+        let mut hasher = sha1_ext::Sha1::new();
+        hasher.update(namespace.0.as_bytes());
+        hasher.update(name);
+
+        let digest = hasher.digest().bytes();
+        let mut bytes = [0u8; 16];
+        bytes.copy_from_slice(&digest[..16]);
+
+        bytes[6] = (bytes[6] & 0x0f) | 0x50;
+        bytes[8] = (bytes[8] & 0x3f) | 0x80;
+
+        UUID(uuid_ext::Uuid::from_bytes(&bytes).expect("16 bytes form a valid UUID"))
+    }
+
+    /// Generates a time-ordered (version 7) UUID by packing a 48-bit
+    /// Unix-millisecond timestamp into the high bytes, followed by random
+    /// bits. Because the timestamp occupies the most significant bits,
+    /// version 7 UUIDs generated in a later millisecond sort lexicographically
+    /// after ones generated in an earlier millisecond, making them well suited
+    /// as database primary keys minted inside request handlers. UUIDs
+    /// generated within the same millisecond are ordered only by their random
+    /// bits, so their relative order is not defined.
+    pub fn new_v7() -> UUID {
+        let since_epoch = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("system time is after the Unix epoch");
+        let millis = since_epoch.as_secs() * 1000
+            + since_epoch.subsec_millis() as u64;
+
+        let mut bytes = [0u8; 16];
+        bytes[0] = (millis >> 40) as u8;
+        bytes[1] = (millis >> 32) as u8;
+        bytes[2] = (millis >> 24) as u8;
+        bytes[3] = (millis >> 16) as u8;
+        bytes[4] = (millis >> 8) as u8;
+        bytes[5] = millis as u8;
+
+        let random: [u8; 10] = rand_ext::random();
+        bytes[6..16].copy_from_slice(&random);
+
+        bytes[6] = (bytes[6] & 0x0f) | 0x70;
+        bytes[8] = (bytes[8] & 0x3f) | 0x80;
+
+        UUID(uuid_ext::Uuid::from_bytes(&bytes).expect("16 bytes form a valid UUID"))
+    }
+
+    /// Returns an adapter that formats this UUID in the canonical hyphenated
+    /// form, e.g. `c1aa1e3b-9614-4895-9ebd-705255fa5bc2`. Equivalent to the
+    /// `Display` implementation, but useful when a representation needs to
+    /// be chosen explicitly alongside `to_simple`, `to_urn`, or `to_braced`.
+    #[inline(always)]
+    pub fn to_hyphenated(&self) -> Hyphenated<'_> {
+        Hyphenated(self)
+    }
+
+    /// Returns an adapter that formats this UUID in the simple form with no
+    /// hyphens, e.g. `c1aa1e3b961448959ebd705255fa5bc2`.
+    #[inline(always)]
+    pub fn to_simple(&self) -> Simple<'_> {
+        Simple(self)
+    }
+
+    /// Returns an adapter that formats this UUID in the URN form, e.g.
+    /// `urn:uuid:c1aa1e3b-9614-4895-9ebd-705255fa5bc2`.
+    #[inline(always)]
+    pub fn to_urn(&self) -> Urn<'_> {
+        Urn(self)
+    }
+
+    /// Returns an adapter that formats this UUID wrapped in braces, e.g.
+    /// `{c1aa1e3b-9614-4895-9ebd-705255fa5bc2}`.
+    #[inline(always)]
+    pub fn to_braced(&self) -> Braced<'_> {
+        Braced(self)
+    }
+}
+
+/// Formats a `UUID` in the simple form with no hyphens. See
+/// [`UUID::to_simple`](struct.UUID.html#method.to_simple).
+pub struct Simple<'a>(&'a UUID);
+
+impl<'a> fmt::Display for Simple<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for byte in (self.0).0.as_bytes() {
+            try!(write!(f, "{:02x}", byte));
+        }
+
+        Ok(())
+    }
+}
+
+/// Formats a `UUID` in the canonical hyphenated form. See
+/// [`UUID::to_hyphenated`](struct.UUID.html#method.to_hyphenated).
+pub struct Hyphenated<'a>(&'a UUID);
+
+impl<'a> fmt::Display for Hyphenated<'a> {
+    #[inline(always)]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        (self.0).0.fmt(f)
+    }
+}
+
+/// Formats a `UUID` in the URN form (`urn:uuid:...`). See
+/// [`UUID::to_urn`](struct.UUID.html#method.to_urn).
+pub struct Urn<'a>(&'a UUID);
+
+impl<'a> fmt::Display for Urn<'a> {
+    #[inline(always)]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "urn:uuid:{}", (self.0).0)
+    }
+}
+
+/// Formats a `UUID` wrapped in braces (`{...}`). See
+/// [`UUID::to_braced`](struct.UUID.html#method.to_braced).
+pub struct Braced<'a>(&'a UUID);
+
+impl<'a> fmt::Display for Braced<'a> {
+    #[inline(always)]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{{{}}}", (self.0).0)
+    }
 }
 
 impl fmt::Display for UUID {
@@ -82,6 +240,8 @@ impl<'a> FromParam<'a> for UUID {
     type Error = UuidParseError;
 
     /// A value is successfully parsed if `param` is a properly formatted UUID.
+    /// The canonical hyphenated form, the simple 32-hex-digit form, the URN
+    /// form (`urn:uuid:...`), and the braced (`{...}`) form are all accepted.
     /// Otherwise, a `UuidParseError` is returned.
     #[inline(always)]
     fn from_param(param: &'a RawStr) -> Result<UUID, Self::Error> {
@@ -93,7 +253,9 @@ impl<'v> FromFormValue<'v> for UUID {
     type Error = &'v RawStr;
 
     /// A value is successfully parsed if `form_value` is a properly formatted
-    /// UUID. Otherwise, the raw form value is returned.
+    /// UUID. The canonical hyphenated form, the simple 32-hex-digit form, the
+    /// URN form (`urn:uuid:...`), and the braced (`{...}`) form are all
+    /// accepted. Otherwise, the raw form value is returned.
     #[inline(always)]
     fn from_form_value(form_value: &'v RawStr) -> Result<UUID, &'v RawStr> {
         form_value.parse().map_err(|_| form_value)
@@ -103,8 +265,32 @@ impl<'v> FromFormValue<'v> for UUID {
 impl FromStr for UUID {
     type Err = UuidParseError;
 
+    /// Parses a UUID from any of its common string representations: the
+    /// canonical hyphenated form (`c1aa1e3b-9614-4895-9ebd-705255fa5bc2`),
+    /// the simple form with no hyphens (`c1aa1e3b961448959ebd705255fa5bc2`),
+    /// the URN form (`urn:uuid:c1aa1e3b-9614-4895-9ebd-705255fa5bc2`), or any
+    /// of the above wrapped in braces (`{c1aa1e3b-...}`).
     #[inline]
     fn from_str(s: &str) -> Result<UUID, Self::Err> {
+        let s = if s.starts_with('{') && s.ends_with('}') {
+            &s[1..s.len() - 1]
+        } else {
+            s
+        };
+
+        let s = if s.get(..9).is_some_and(|prefix| prefix.eq_ignore_ascii_case("urn:uuid:")) {
+            &s[9..]
+        } else {
+            s
+        };
+
+        if s.len() == 32 && s.bytes().all(|b| b.is_ascii_hexdigit()) {
+            let hyphenated = format!("{}-{}-{}-{}-{}",
+                &s[0..8], &s[8..12], &s[12..16], &s[16..20], &s[20..32]);
+
+            return Ok(UUID(try!(hyphenated.parse())));
+        }
+
         Ok(UUID(try!(s.parse())))
     }
 }
@@ -124,6 +310,72 @@ impl PartialEq<uuid_ext::Uuid> for UUID {
     }
 }
 
+// Delegating to `self.0` here requires the `uuid` crate's own `serde`
+// Cargo feature to be enabled alongside this crate's `serde` feature.
+#[cfg(feature = "serde")]
+impl serde_ext::Serialize for UUID {
+    #[inline(always)]
+    fn serialize<S: serde_ext::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde_ext::Deserialize<'de> for UUID {
+    #[inline(always)]
+    fn deserialize<D: serde_ext::Deserializer<'de>>(deserializer: D) -> Result<UUID, D::Error> {
+        uuid_ext::Uuid::deserialize(deserializer).map(UUID)
+    }
+}
+
+/// Serializes and deserializes a `UUID` as a compact `[u8; 16]` byte array
+/// instead of its 36-character hyphenated string form. Useful for binary
+/// formats like MsgPack where the string form wastes space.
+///
+/// # Usage
+///
+/// ```rust,ignore
+/// #[derive(Serialize, Deserialize)]
+/// struct Item {
+///     #[serde(with = "rocket_contrib::uuid::compact")]
+///     id: UUID,
+/// }
+/// ```
+#[cfg(feature = "serde")]
+pub mod compact {
+    use std::fmt;
+
+    use super::{UUID, uuid_ext, serde_ext};
+    use self::serde_ext::{Serializer, Deserializer};
+    use self::serde_ext::de::{self, Visitor};
+
+    /// Serializes a `UUID` as a `[u8; 16]` byte array.
+    pub fn serialize<S: Serializer>(uuid: &UUID, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(uuid.0.as_bytes())
+    }
+
+    /// Deserializes a `UUID` from a `[u8; 16]` byte array.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<UUID, D::Error> {
+        struct CompactVisitor;
+
+        impl<'de> Visitor<'de> for CompactVisitor {
+            type Value = UUID;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("16 bytes of a UUID")
+            }
+
+            fn visit_bytes<E: de::Error>(self, bytes: &[u8]) -> Result<UUID, E> {
+                uuid_ext::Uuid::from_bytes(bytes)
+                    .map(UUID)
+                    .map_err(|_| E::invalid_length(bytes.len(), &self))
+            }
+        }
+
+        deserializer.deserialize_bytes(CompactVisitor)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::uuid_ext;
@@ -168,4 +420,102 @@ mod test {
         let uuid_result = UUID::from_param(uuid_str.into());
         assert_eq!(uuid_result, Err(UuidParseError::InvalidLength(37)));
     }
+
+    #[test]
+    fn test_from_param_invalid_multibyte_boundary() {
+        // A multi-byte UTF-8 character straddling the `urn:uuid:` prefix
+        // check must be rejected, not panic on a non-char-boundary slice.
+        let uuid_str = "12345678\u{20ac}aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+        let uuid_result = UUID::from_param(uuid_str.into());
+        assert!(uuid_result.is_err());
+    }
+
+    #[test]
+    fn test_from_param_simple() {
+        let hyphenated = "c1aa1e3b-9614-4895-9ebd-705255fa5bc2";
+        let simple = "c1aa1e3b961448959ebd705255fa5bc2";
+        let uuid_wrapper = UUID::from_param(simple.into()).unwrap();
+        assert_eq!(hyphenated, uuid_wrapper.to_string())
+    }
+
+    #[test]
+    fn test_from_param_urn() {
+        let hyphenated = "c1aa1e3b-9614-4895-9ebd-705255fa5bc2";
+        let urn = "urn:uuid:c1aa1e3b-9614-4895-9ebd-705255fa5bc2";
+        let uuid_wrapper = UUID::from_param(urn.into()).unwrap();
+        assert_eq!(hyphenated, uuid_wrapper.to_string())
+    }
+
+    #[test]
+    fn test_from_param_braced() {
+        let hyphenated = "c1aa1e3b-9614-4895-9ebd-705255fa5bc2";
+        let braced = "{c1aa1e3b-9614-4895-9ebd-705255fa5bc2}";
+        let uuid_wrapper = UUID::from_param(braced.into()).unwrap();
+        assert_eq!(hyphenated, uuid_wrapper.to_string())
+    }
+
+    #[test]
+    fn test_new_v4_is_version_4() {
+        let uuid = UUID::new_v4();
+        assert_eq!(uuid.0.get_version_num(), 4);
+    }
+
+    #[test]
+    fn test_new_v5_is_deterministic() {
+        let namespace = UUID::new_v4();
+        let first = UUID::new_v5(&namespace, b"rocket");
+        let second = UUID::new_v5(&namespace, b"rocket");
+        assert_eq!(first, second);
+        assert_eq!(first.0.get_version_num(), 5);
+    }
+
+    #[test]
+    fn test_new_v5_distinguishes_names() {
+        let namespace = UUID::new_v4();
+        let first = UUID::new_v5(&namespace, b"rocket");
+        let second = UUID::new_v5(&namespace, b"launch");
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn test_new_v7_is_version_7_and_sortable() {
+        let first = UUID::new_v7();
+        assert_eq!(first.0.get_version_num(), 7);
+
+        // Two calls landing in the same millisecond only differ in their
+        // random tail bits, so ordering isn't guaranteed unless the
+        // timestamps themselves differ; force a millisecond gap instead of
+        // relying on wall-clock timing between back-to-back calls.
+        ::std::thread::sleep(::std::time::Duration::from_millis(2));
+        let second = UUID::new_v7();
+        assert!(first <= second);
+    }
+
+    #[test]
+    fn test_to_simple() {
+        let uuid_str = "c1aa1e3b-9614-4895-9ebd-705255fa5bc2";
+        let uuid_wrapper = UUID::from_str(uuid_str).unwrap();
+        assert_eq!(uuid_wrapper.to_simple().to_string(), "c1aa1e3b961448959ebd705255fa5bc2");
+    }
+
+    #[test]
+    fn test_to_hyphenated() {
+        let uuid_str = "c1aa1e3b-9614-4895-9ebd-705255fa5bc2";
+        let uuid_wrapper = UUID::from_str(uuid_str).unwrap();
+        assert_eq!(uuid_wrapper.to_hyphenated().to_string(), uuid_str);
+    }
+
+    #[test]
+    fn test_to_urn() {
+        let uuid_str = "c1aa1e3b-9614-4895-9ebd-705255fa5bc2";
+        let uuid_wrapper = UUID::from_str(uuid_str).unwrap();
+        assert_eq!(uuid_wrapper.to_urn().to_string(), format!("urn:uuid:{}", uuid_str));
+    }
+
+    #[test]
+    fn test_to_braced() {
+        let uuid_str = "c1aa1e3b-9614-4895-9ebd-705255fa5bc2";
+        let uuid_wrapper = UUID::from_str(uuid_str).unwrap();
+        assert_eq!(uuid_wrapper.to_braced().to_string(), format!("{{{}}}", uuid_str));
+    }
 }